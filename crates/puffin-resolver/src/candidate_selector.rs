@@ -0,0 +1,122 @@
+use std::collections::BTreeSet;
+
+use pubgrub::range::Range;
+
+use pep440_rs::Version;
+use puffin_normalize::PackageName;
+
+use crate::error::PackageInLockFile;
+
+/// Chooses which version of a package to try next during resolution.
+#[derive(Debug, Default, Clone)]
+pub struct CandidateSelector {
+    /// Versions pinned in the lockfile prior to resolution, keyed by package name.
+    locked_versions: indexmap::IndexMap<PackageName, Version>,
+}
+
+impl CandidateSelector {
+    /// Create a selector that prefers the given locked versions when they still satisfy the
+    /// requirements being solved, so that a resolution that previously succeeded doesn't change
+    /// versions gratuitously on a repeat run.
+    pub fn new(locked_dependencies: Option<&[PackageInLockFile]>) -> Self {
+        Self {
+            locked_versions: locked_dependencies
+                .into_iter()
+                .flatten()
+                .map(|package| (package.name.clone(), package.version.clone()))
+                .collect(),
+        }
+    }
+
+    /// Select the preferred version of `package` from the set of versions satisfying `range`.
+    ///
+    /// If `package` was pinned in the lockfile and that pin still satisfies `range`, it's
+    /// preferred over any other candidate. Otherwise, the highest version satisfying `range` is
+    /// chosen, matching the resolver's default "prefer the latest compatible version" behavior.
+    pub fn select<'a>(
+        &self,
+        package: &PackageName,
+        range: &Range<Version>,
+        versions: &'a BTreeSet<Version>,
+    ) -> Option<&'a Version> {
+        if let Some(locked) = self.locked_versions.get(package) {
+            if range.contains(locked) {
+                if let Some(version) = versions.get(locked) {
+                    return Some(version);
+                }
+            }
+        }
+        versions
+            .iter()
+            .rev()
+            .find(|version| range.contains(version))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn versions(values: &[&str]) -> BTreeSet<Version> {
+        values
+            .iter()
+            .map(|value| Version::from_str(value).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn prefers_the_locked_version_when_it_still_satisfies_the_range() {
+        let name = PackageName::from_str("foo").unwrap();
+        let selector = CandidateSelector::new(Some(&[PackageInLockFile {
+            name: name.clone(),
+            version: Version::from_str("1.2.0").unwrap(),
+        }]));
+
+        let selected = selector
+            .select(
+                &name,
+                &Range::full(),
+                &versions(&["1.0.0", "1.2.0", "1.5.0"]),
+            )
+            .unwrap();
+
+        assert_eq!(selected, &Version::from_str("1.2.0").unwrap());
+    }
+
+    #[test]
+    fn falls_back_to_the_highest_version_when_nothing_is_locked() {
+        let name = PackageName::from_str("foo").unwrap();
+        let selector = CandidateSelector::new(None);
+
+        let selected = selector
+            .select(
+                &name,
+                &Range::full(),
+                &versions(&["1.0.0", "1.2.0", "1.5.0"]),
+            )
+            .unwrap();
+
+        assert_eq!(selected, &Version::from_str("1.5.0").unwrap());
+    }
+
+    #[test]
+    fn falls_back_to_the_highest_version_when_the_locked_version_no_longer_satisfies_the_range() {
+        let name = PackageName::from_str("foo").unwrap();
+        let selector = CandidateSelector::new(Some(&[PackageInLockFile {
+            name: name.clone(),
+            version: Version::from_str("1.0.0").unwrap(),
+        }]));
+
+        let selected = selector
+            .select(
+                &name,
+                &Range::higher_than(Version::from_str("2.0.0").unwrap()),
+                &versions(&["2.0.0", "2.1.0"]),
+            )
+            .unwrap();
+
+        assert_eq!(selected, &Version::from_str("2.1.0").unwrap());
+    }
+}
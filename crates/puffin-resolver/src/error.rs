@@ -1,16 +1,16 @@
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 use std::convert::Infallible;
 use std::fmt::Formatter;
 
 use dashmap::DashSet;
 use indexmap::IndexMap;
 use pubgrub::range::Range;
-use pubgrub::report::{DefaultStringReporter, DerivationTree, Reporter};
+use pubgrub::report::{DefaultStringReporter, DerivationTree, External, Reporter};
 use url::Url;
 
 use distribution_types::{BuiltDist, PathBuiltDist, PathSourceDist, SourceDist};
 use once_map::OnceMap;
-use pep440_rs::Version;
+use pep440_rs::{Version, VersionSpecifiers};
 use pep508_rs::Requirement;
 use puffin_normalize::PackageName;
 
@@ -115,6 +115,8 @@ impl From<pubgrub::error::PubGrubError<PubGrubPackage, Range<Version>, Infallibl
                     available_versions: IndexMap::default(),
                     selector: None,
                     python_requirement: None,
+                    locked_versions: IndexMap::default(),
+                    python_incompatible_versions: IndexMap::default(),
                 })
             }
             pubgrub::error::PubGrubError::SelfDependency { package, version } => {
@@ -127,6 +129,18 @@ impl From<pubgrub::error::PubGrubError<PubGrubPackage, Range<Version>, Infallibl
     }
 }
 
+/// A version of a package that was pinned in the lockfile prior to resolution.
+///
+/// Passed to [`CandidateSelector::new`](crate::candidate_selector::CandidateSelector::new) so
+/// that candidate selection prefers these versions when they still satisfy the requirements being
+/// solved, and to [`NoSolutionError::with_locked`] so that a resolution failure caused by a stale
+/// pin is reported distinctly from an ordinary unsatisfiable conflict.
+#[derive(Debug, Clone)]
+pub struct PackageInLockFile {
+    pub name: PackageName,
+    pub version: Version,
+}
+
 /// A wrapper around [`pubgrub::error::PubGrubError::NoSolution`] that displays a resolution failure report.
 #[derive(Debug)]
 pub struct NoSolutionError {
@@ -134,32 +148,219 @@ pub struct NoSolutionError {
     available_versions: IndexMap<PubGrubPackage, BTreeSet<Version>>,
     selector: Option<CandidateSelector>,
     python_requirement: Option<PythonRequirement>,
+    locked_versions: IndexMap<PackageName, Version>,
+    /// For each package whose every known version is incompatible with the target Python
+    /// version, the `requires-python` constraint of each of those versions. Packages with at
+    /// least one compatible version are not recorded.
+    python_incompatible_versions: IndexMap<PackageName, BTreeMap<Version, VersionSpecifiers>>,
 }
 
 impl std::error::Error for NoSolutionError {}
 
 impl std::fmt::Display for NoSolutionError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // Collapse chains of "no versions" derivations into a single term before rendering, so
+        // that a simple "no versions of X satisfy Y" doesn't show up as a long, repetitive
+        // derivation chain.
+        let derivation_tree = collapse_no_versions(self.derivation_tree.clone());
+
         // Write the derivation report.
         let formatter = PubGrubReportFormatter {
             available_versions: &self.available_versions,
             python_requirement: self.python_requirement.as_ref(),
         };
-        let report =
-            DefaultStringReporter::report_with_formatter(&self.derivation_tree, &formatter);
+        let report = DefaultStringReporter::report_with_formatter(&derivation_tree, &formatter);
         write!(f, "{report}")?;
 
         // Include any additional hints.
         if let Some(selector) = &self.selector {
-            for hint in formatter.hints(&self.derivation_tree, selector) {
+            for hint in formatter.hints(&derivation_tree, selector) {
                 write!(f, "\n\n{hint}")?;
             }
         }
 
+        // If the failure is rooted in a version pinned by the lockfile no longer satisfying a
+        // new requirement, call that out specifically rather than leaving it to look like an
+        // ordinary unsatisfiable conflict.
+        let mut locked_conflicts = Vec::new();
+        collect_locked_conflicts(
+            &derivation_tree,
+            &self.locked_versions,
+            &self.available_versions,
+            &mut locked_conflicts,
+        );
+        for hint in locked_conflicts {
+            write!(f, "\n\n{hint}")?;
+        }
+
+        // If a package has "no versions" purely because every version we know about requires an
+        // incompatible Python version, call out the Python version that would unblock it.
+        let mut python_hints = Vec::new();
+        collect_python_hints(
+            &derivation_tree,
+            self.python_requirement
+                .as_ref()
+                .map(PythonRequirement::target),
+            &self.python_incompatible_versions,
+            &mut python_hints,
+        );
+        for hint in python_hints {
+            write!(f, "\n\n{hint}")?;
+        }
+
         Ok(())
     }
 }
 
+/// Walk a [`DerivationTree`] looking for [`External::NoVersions`] nodes whose package was
+/// excluded solely because every known version requires an incompatible Python version, and,
+/// where the lowest `requires-python` floor among those versions is above the target, push a hint
+/// naming that Python version.
+fn collect_python_hints(
+    tree: &DerivationTree<PubGrubPackage, Range<Version>>,
+    target: Option<&Version>,
+    python_incompatible_versions: &IndexMap<PackageName, BTreeMap<Version, VersionSpecifiers>>,
+    hints: &mut Vec<String>,
+) {
+    match tree {
+        DerivationTree::External(External::NoVersions(package, _)) => {
+            let PubGrubPackage::Package(name, ..) = package else {
+                return;
+            };
+            let Some(target) = target else {
+                return;
+            };
+            let Some(excluded) = python_incompatible_versions.get(name) else {
+                return;
+            };
+            // The minimal Python bump that unblocks `name` is the lowest `requires-python` floor
+            // across every excluded version, not the newest version's floor: newer releases
+            // typically *raise* the floor, so reading it off the newest version would recommend
+            // a larger bump than necessary.
+            // A floor at or below the target wouldn't unblock anything, so no hint is given then.
+            if let Some(minimum) =
+                minimum_python_floor(excluded).filter(|minimum| minimum > target)
+            {
+                hints.push(format!(
+                    "hint: All versions of `{name}` require at least Python {minimum}, but \
+                     you're targeting Python {target}. Re-run with `--python-version {minimum}` \
+                     (or newer) to make `{name}` available."
+                ));
+            }
+        }
+        DerivationTree::External(_) => {}
+        DerivationTree::Derived(derived) => {
+            collect_python_hints(&derived.cause1, target, python_incompatible_versions, hints);
+            collect_python_hints(&derived.cause2, target, python_incompatible_versions, hints);
+        }
+    }
+}
+
+/// Find the lowest `requires-python` lower bound across a package's excluded versions, i.e. the
+/// minimal Python version that would make at least one of them installable.
+fn minimum_python_floor(excluded: &BTreeMap<Version, VersionSpecifiers>) -> Option<Version> {
+    excluded
+        .values()
+        .filter_map(|requires_python| {
+            requires_python
+                .iter()
+                .filter(|specifier| {
+                    matches!(
+                        specifier.operator(),
+                        pep440_rs::Operator::GreaterThanEqual | pep440_rs::Operator::GreaterThan
+                    )
+                })
+                .map(|specifier| specifier.version().clone())
+                .min()
+        })
+        .min()
+}
+
+/// Walk a [`DerivationTree`] looking for [`External::NoVersions`] nodes whose package is pinned
+/// in the lockfile at a version outside of the required range, and push a dedicated hint for
+/// each one explaining the pin versus the new requirement.
+fn collect_locked_conflicts(
+    tree: &DerivationTree<PubGrubPackage, Range<Version>>,
+    locked_versions: &IndexMap<PackageName, Version>,
+    available_versions: &IndexMap<PubGrubPackage, BTreeSet<Version>>,
+    hints: &mut Vec<String>,
+) {
+    match tree {
+        DerivationTree::External(External::NoVersions(package, range)) => {
+            let PubGrubPackage::Package(name, ..) = package else {
+                return;
+            };
+            let Some(locked) = locked_versions.get(name) else {
+                return;
+            };
+            if range.contains(locked) {
+                return;
+            }
+            match available_versions
+                .get(package)
+                .into_iter()
+                .flatten()
+                .filter(|version| range.contains(version))
+                .max()
+            {
+                Some(newest) => hints.push(format!(
+                    "hint: Your lockfile pins `{name}=={locked}`, but the new requirement needs \
+                     `{name} {range}`. Consider relocking against `{name}=={newest}`."
+                )),
+                None => hints.push(format!(
+                    "hint: Your lockfile pins `{name}=={locked}`, but the new requirement needs \
+                     `{name} {range}`, and no available version satisfies it."
+                )),
+            }
+        }
+        DerivationTree::External(_) => {}
+        DerivationTree::Derived(derived) => {
+            collect_locked_conflicts(&derived.cause1, locked_versions, available_versions, hints);
+            collect_locked_conflicts(&derived.cause2, locked_versions, available_versions, hints);
+        }
+    }
+}
+
+/// Recursively collapse chains of [`External::NoVersions`] derivations in a [`DerivationTree`].
+///
+/// PubGrub's derivation trees can end up with long runs of [`DerivationTree::Derived`] nodes
+/// whose only purpose is to restate that some package has no versions matching a range. Rendered
+/// as-is, this produces a verbose chain of near-duplicate incompatibilities. This pass merges
+/// such chains into the single term they're ultimately explaining, so the reporter can emit one
+/// "there are no versions of X that satisfy Y" line instead.
+///
+/// [`External::FromDependencyOf`] and [`DerivationTree::External(External::NotRoot(..))`] nodes
+/// are left untouched, since those represent genuine dependency conflicts that should still be
+/// explained in full.
+fn collapse_no_versions(
+    tree: DerivationTree<PubGrubPackage, Range<Version>>,
+) -> DerivationTree<PubGrubPackage, Range<Version>> {
+    match tree {
+        DerivationTree::External(_) => tree,
+        DerivationTree::Derived(derived) => {
+            let cause1 = collapse_no_versions(*derived.cause1);
+            let cause2 = collapse_no_versions(*derived.cause2);
+
+            // Only collapse this node when *both* sides have reduced to a bare "no versions"
+            // term; collapsing whenever just one side did would silently drop the other side
+            // even when it's a `FromDependencyOf`/`NotRoot` explanation that must be kept intact.
+            // When both sides agree, keep the first as the root cause.
+            match (&cause1, &cause2) {
+                (
+                    DerivationTree::External(External::NoVersions(..)),
+                    DerivationTree::External(External::NoVersions(..)),
+                ) => cause1,
+                _ => DerivationTree::Derived(pubgrub::report::Derived {
+                    terms: derived.terms,
+                    shared_id: derived.shared_id,
+                    cause1: Box::new(cause1),
+                    cause2: Box::new(cause2),
+                }),
+            }
+        }
+    }
+}
+
 impl NoSolutionError {
     /// Update the available versions attached to the error using the given package version index.
     ///
@@ -172,6 +373,7 @@ impl NoSolutionError {
         package_versions: &OnceMap<PackageName, VersionMap>,
     ) -> Self {
         let mut available_versions = IndexMap::default();
+        let mut python_incompatible_versions = IndexMap::default();
         for package in self.derivation_tree.packages() {
             match package {
                 PubGrubPackage::Root(_) => {}
@@ -194,19 +396,32 @@ impl NoSolutionError {
                     // we represent the state of the resolver at the time of failure.
                     if visited.contains(name) {
                         if let Some(version_map) = package_versions.get(name) {
-                            available_versions.insert(
-                                package.clone(),
-                                version_map
-                                    .iter()
-                                    .map(|(version, _)| version.clone())
-                                    .collect(),
-                            );
+                            let mut versions = BTreeSet::new();
+                            let mut excluded_by_python = BTreeMap::new();
+                            for (version, file) in version_map.iter() {
+                                versions.insert(version.clone());
+                                if let Some(requires_python) = file.requires_python() {
+                                    if !requires_python.contains(python_requirement.target()) {
+                                        excluded_by_python
+                                            .insert(version.clone(), requires_python.clone());
+                                    }
+                                }
+                            }
+                            if !versions.is_empty() && excluded_by_python.len() == versions.len() {
+                                // Every known version of this package is incompatible with the
+                                // target Python version, so it's the sole reason this package
+                                // has "no versions" in the derivation tree.
+                                python_incompatible_versions
+                                    .insert(name.clone(), excluded_by_python);
+                            }
+                            available_versions.insert(package.clone(), versions);
                         }
                     }
                 }
             }
         }
         self.available_versions = available_versions;
+        self.python_incompatible_versions = python_incompatible_versions;
         self
     }
 
@@ -226,4 +441,346 @@ impl NoSolutionError {
         self.python_requirement = Some(python_requirement.clone());
         self
     }
+
+    /// Attach the set of package versions pinned in the lockfile prior to resolution, so that a
+    /// failure caused by a stale pin can be distinguished from an ordinary unsatisfiable
+    /// conflict.
+    ///
+    /// Pass the same `locked_dependencies` to
+    /// [`CandidateSelector::new`](crate::candidate_selector::CandidateSelector::new) so that
+    /// candidate selection also prefers them during resolution; the two are independent calls
+    /// because `NoSolutionError` is only ever constructed after resolution has already failed.
+    #[must_use]
+    pub(crate) fn with_locked(mut self, locked_dependencies: Option<&[PackageInLockFile]>) -> Self {
+        self.locked_versions = locked_dependencies
+            .into_iter()
+            .flatten()
+            .map(|package| (package.name.clone(), package.version.clone()))
+            .collect();
+        self
+    }
+
+    /// Build a machine-readable report of this resolution failure, for callers (IDEs, CI bots)
+    /// that want to render their own UI instead of parsing the [`Display`](std::fmt::Display)
+    /// output.
+    pub fn to_report(&self) -> NoSolutionReport {
+        NoSolutionReport {
+            derivation: report_node(&self.derivation_tree),
+            available_versions: self
+                .available_versions
+                .iter()
+                .map(|(package, versions)| (package.to_string(), versions.clone()))
+                .collect(),
+            python_requirement: self
+                .python_requirement
+                .as_ref()
+                .map(|python_requirement| python_requirement.target().to_string()),
+        }
+    }
+}
+
+/// A serializable representation of a [`NoSolutionError`]'s derivation tree.
+#[derive(Debug, serde::Serialize)]
+pub struct NoSolutionReport {
+    pub derivation: NoSolutionReportNode,
+    pub available_versions: IndexMap<String, BTreeSet<Version>>,
+    pub python_requirement: Option<String>,
+}
+
+/// A single incompatibility in a [`NoSolutionReport`]'s derivation tree.
+#[derive(Debug, serde::Serialize)]
+pub struct NoSolutionReportNode {
+    pub package: Option<String>,
+    pub version_range: Option<String>,
+    pub cause: NoSolutionReportCause,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dependency: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dependency_range: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<NoSolutionReportNode>,
+}
+
+/// The kind of incompatibility a [`NoSolutionReportNode`] represents.
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum NoSolutionReportCause {
+    /// The root package requires a version that isn't the one being resolved.
+    Root,
+    /// No versions of `package` are available within `version_range`.
+    NoVersions,
+    /// `package` is restricted to `version_range` because `dependency` requires it.
+    DependencyOf,
+    /// Two incompatibilities were combined to derive a new one; see `children`.
+    Conjunction,
+}
+
+/// Recursively convert a [`DerivationTree`] into its [`NoSolutionReportNode`] representation.
+fn report_node(tree: &DerivationTree<PubGrubPackage, Range<Version>>) -> NoSolutionReportNode {
+    match tree {
+        DerivationTree::External(External::NotRoot(package, version)) => NoSolutionReportNode {
+            package: Some(package.to_string()),
+            version_range: Some(version.to_string()),
+            cause: NoSolutionReportCause::Root,
+            dependency: None,
+            dependency_range: None,
+            children: Vec::new(),
+        },
+        DerivationTree::External(External::NoVersions(package, range)) => NoSolutionReportNode {
+            package: Some(package.to_string()),
+            version_range: Some(range.to_string()),
+            cause: NoSolutionReportCause::NoVersions,
+            dependency: None,
+            dependency_range: None,
+            children: Vec::new(),
+        },
+        DerivationTree::External(External::FromDependencyOf(
+            package,
+            range,
+            dependency,
+            dependency_range,
+        )) => NoSolutionReportNode {
+            package: Some(package.to_string()),
+            version_range: Some(range.to_string()),
+            cause: NoSolutionReportCause::DependencyOf,
+            dependency: Some(dependency.to_string()),
+            dependency_range: Some(dependency_range.to_string()),
+            children: Vec::new(),
+        },
+        DerivationTree::Derived(derived) => NoSolutionReportNode {
+            package: None,
+            version_range: None,
+            cause: NoSolutionReportCause::Conjunction,
+            dependency: None,
+            dependency_range: None,
+            children: vec![report_node(&derived.cause1), report_node(&derived.cause2)],
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    /// Build a `PubGrubPackage::Package` for `name`, with no extra or URL pin.
+    fn package(name: &str) -> PubGrubPackage {
+        PubGrubPackage::Package(PackageName::from_str(name).unwrap(), None, None)
+    }
+
+    fn no_versions(package: PubGrubPackage) -> DerivationTree<PubGrubPackage, Range<Version>> {
+        DerivationTree::External(External::NoVersions(package, Range::full()))
+    }
+
+    fn from_dependency_of(
+        package: PubGrubPackage,
+        dependency: PubGrubPackage,
+    ) -> DerivationTree<PubGrubPackage, Range<Version>> {
+        DerivationTree::External(External::FromDependencyOf(
+            package,
+            Range::full(),
+            dependency,
+            Range::full(),
+        ))
+    }
+
+    fn derived(
+        cause1: DerivationTree<PubGrubPackage, Range<Version>>,
+        cause2: DerivationTree<PubGrubPackage, Range<Version>>,
+    ) -> DerivationTree<PubGrubPackage, Range<Version>> {
+        DerivationTree::Derived(pubgrub::report::Derived {
+            terms: Default::default(),
+            shared_id: None,
+            cause1: Box::new(cause1),
+            cause2: Box::new(cause2),
+        })
+    }
+
+    #[test]
+    fn collapse_no_versions_merges_a_no_versions_chain() {
+        let tree = derived(
+            no_versions(package("foo")),
+            derived(no_versions(package("foo")), no_versions(package("foo"))),
+        );
+
+        let collapsed = collapse_no_versions(tree);
+
+        assert!(matches!(
+            collapsed,
+            DerivationTree::External(External::NoVersions(..))
+        ));
+    }
+
+    #[test]
+    fn collapse_no_versions_preserves_a_genuine_dependency_conflict() {
+        // `myapp` depends on `foo`, but there are no versions of `foo` that satisfy it: the
+        // `FromDependencyOf` side must survive so the reason `foo` was even considered is not
+        // silently dropped.
+        let tree = derived(
+            from_dependency_of(package("myapp"), package("foo")),
+            no_versions(package("foo")),
+        );
+
+        let collapsed = collapse_no_versions(tree);
+
+        match collapsed {
+            DerivationTree::Derived(derived) => {
+                assert!(matches!(
+                    *derived.cause1,
+                    DerivationTree::External(External::FromDependencyOf(..))
+                ));
+                assert!(matches!(
+                    *derived.cause2,
+                    DerivationTree::External(External::NoVersions(..))
+                ));
+            }
+            DerivationTree::External(_) => panic!("expected the Derived node to survive intact"),
+        }
+    }
+
+    #[test]
+    fn collect_locked_conflicts_hints_when_the_locked_version_is_out_of_range() {
+        let name = PackageName::from_str("foo").unwrap();
+        let locked = Version::from_str("1.0.0").unwrap();
+        let newer = Version::from_str("2.0.0").unwrap();
+
+        let tree = DerivationTree::External(External::NoVersions(
+            package("foo"),
+            Range::higher_than(Version::from_str("2.0.0").unwrap()),
+        ));
+
+        let mut locked_versions = IndexMap::default();
+        locked_versions.insert(name.clone(), locked.clone());
+
+        let mut available_versions = IndexMap::default();
+        available_versions.insert(package("foo"), BTreeSet::from([locked, newer.clone()]));
+
+        let mut hints = Vec::new();
+        collect_locked_conflicts(&tree, &locked_versions, &available_versions, &mut hints);
+
+        assert_eq!(hints.len(), 1);
+        assert!(hints[0].contains("1.0.0"));
+        assert!(hints[0].contains(&newer.to_string()));
+    }
+
+    #[test]
+    fn collect_locked_conflicts_is_silent_when_the_locked_version_still_satisfies() {
+        let name = PackageName::from_str("foo").unwrap();
+        let locked = Version::from_str("2.5.0").unwrap();
+
+        let tree = DerivationTree::External(External::NoVersions(
+            package("foo"),
+            Range::higher_than(Version::from_str("2.0.0").unwrap()),
+        ));
+
+        let mut locked_versions = IndexMap::default();
+        locked_versions.insert(name, locked);
+
+        let available_versions = IndexMap::default();
+
+        let mut hints = Vec::new();
+        collect_locked_conflicts(&tree, &locked_versions, &available_versions, &mut hints);
+
+        assert!(hints.is_empty());
+    }
+
+    #[test]
+    fn report_node_distinguishes_no_versions_from_dependency_of() {
+        let node = report_node(&no_versions(package("foo")));
+        assert!(matches!(node.cause, NoSolutionReportCause::NoVersions));
+        assert_eq!(node.package.as_deref(), Some("foo"));
+
+        let node = report_node(&from_dependency_of(package("myapp"), package("foo")));
+        assert!(matches!(node.cause, NoSolutionReportCause::DependencyOf));
+        assert_eq!(node.dependency.as_deref(), Some("foo"));
+    }
+
+    #[test]
+    fn report_node_represents_a_conjunction_as_a_parent_with_two_children() {
+        let node = report_node(&derived(
+            no_versions(package("foo")),
+            no_versions(package("bar")),
+        ));
+
+        assert!(matches!(node.cause, NoSolutionReportCause::Conjunction));
+        assert!(node.package.is_none());
+        assert_eq!(node.children.len(), 2);
+    }
+
+    #[test]
+    fn no_solution_report_node_serializes_without_empty_optional_fields() {
+        let node = report_node(&no_versions(package("foo")));
+        let json = serde_json::to_value(&node).unwrap();
+
+        assert_eq!(json["package"], "foo");
+        assert_eq!(json["cause"], "no-versions");
+        // `dependency`/`dependency_range` are irrelevant for this variant and should be omitted
+        // rather than serialized as `null`.
+        assert!(json.get("dependency").is_none());
+        assert!(json.get("children").is_none());
+    }
+
+    #[test]
+    fn minimum_python_floor_picks_the_lowest_bound_not_the_newest_version() {
+        let mut excluded = BTreeMap::new();
+        // The newest version has the *highest* floor; the minimal bump is set by an older,
+        // less-restrictive version instead.
+        excluded.insert(
+            Version::from_str("1.0.0").unwrap(),
+            VersionSpecifiers::from_str(">=3.9").unwrap(),
+        );
+        excluded.insert(
+            Version::from_str("2.0.0").unwrap(),
+            VersionSpecifiers::from_str(">=3.11").unwrap(),
+        );
+
+        let minimum = minimum_python_floor(&excluded).unwrap();
+
+        assert_eq!(minimum, Version::from_str("3.9").unwrap());
+    }
+
+    #[test]
+    fn collect_python_hints_names_the_minimum_required_python_version() {
+        let tree = no_versions(package("foo"));
+
+        let mut excluded = BTreeMap::new();
+        excluded.insert(
+            Version::from_str("1.0.0").unwrap(),
+            VersionSpecifiers::from_str(">=3.9").unwrap(),
+        );
+        let mut python_incompatible_versions = IndexMap::default();
+        python_incompatible_versions.insert(PackageName::from_str("foo").unwrap(), excluded);
+
+        let target = Version::from_str("3.8").unwrap();
+        let mut hints = Vec::new();
+        collect_python_hints(
+            &tree,
+            Some(&target),
+            &python_incompatible_versions,
+            &mut hints,
+        );
+
+        assert_eq!(hints.len(), 1);
+        assert!(hints[0].contains("3.9"));
+        assert!(hints[0].contains("3.8"));
+    }
+
+    #[test]
+    fn collect_python_hints_is_silent_without_a_target() {
+        let tree = no_versions(package("foo"));
+
+        let mut excluded = BTreeMap::new();
+        excluded.insert(
+            Version::from_str("1.0.0").unwrap(),
+            VersionSpecifiers::from_str(">=3.9").unwrap(),
+        );
+        let mut python_incompatible_versions = IndexMap::default();
+        python_incompatible_versions.insert(PackageName::from_str("foo").unwrap(), excluded);
+
+        let mut hints = Vec::new();
+        collect_python_hints(&tree, None, &python_incompatible_versions, &mut hints);
+
+        assert!(hints.is_empty());
+    }
 }